@@ -0,0 +1,795 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use serde_json::Value;
+
+mod parser;
+pub use parser::{parse_and_eval, Expr};
+
+#[derive(Clone, Debug)]
+pub enum WeakType {
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Object(Object),
+    Array(Array),
+    Null,
+    Undefined,
+}
+
+use std::fmt::Formatter;
+impl std::fmt::Display for WeakType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.coerce_to_string())
+    }
+}
+
+impl std::ops::Add for &WeakType {
+    type Output = WeakType;
+
+    fn add(self, rhs: &WeakType) -> Self::Output {
+        match self {
+            WeakType::String(self_str) => match rhs {
+                WeakType::String(rhs_str) => WeakType::from(self_str.to_owned() + rhs_str),
+                WeakType::Undefined => {
+                    WeakType::from(self_str.to_owned() + &rhs.coerce_to_string())
+                }
+                WeakType::Object(_) => {
+                    WeakType::from(self_str.to_owned() + &rhs.coerce_to_string())
+                }
+
+                WeakType::Number(rhs_num) => {
+                    let self_coerced = self.coerce_to_number();
+                    if self_coerced.is_nan() {
+                        WeakType::from(self_str.to_owned() + &rhs_num.to_string())
+                    } else {
+                        WeakType::from(self_coerced + rhs_num)
+                    }
+                }
+                WeakType::Array(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Boolean(_) => {
+                    WeakType::from(self_str.to_owned() + &rhs.coerce_to_string())
+                }
+                WeakType::Null => WeakType::from(self_str.to_owned() + &rhs.coerce_to_string()),
+            },
+
+            WeakType::Number(self_num) => {
+                let rhs_coerced = rhs.coerce_to_number();
+                if rhs_coerced.is_nan() {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                } else {
+                    WeakType::from(self_num + rhs_coerced)
+                }
+            }
+            WeakType::Object(_) => match rhs {
+                WeakType::String(rhs_str) => WeakType::from(self.coerce_to_string() + rhs_str),
+                WeakType::Number(_) => WeakType::from(f64::NAN),
+                WeakType::Object(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Undefined => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Array(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Boolean(_) => WeakType::from(f64::NAN),
+                WeakType::Null => WeakType::from(f64::NAN),
+            },
+            WeakType::Undefined => match rhs {
+                WeakType::String(rhs_str) => WeakType::from(self.coerce_to_string() + rhs_str),
+                WeakType::Number(_) => WeakType::from(f64::NAN),
+                WeakType::Undefined => WeakType::from(f64::NAN),
+                WeakType::Object(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Array(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                WeakType::Boolean(_) => WeakType::from(f64::NAN),
+                WeakType::Null => WeakType::from(f64::NAN),
+            },
+            WeakType::Array(_) => WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string()),
+            WeakType::Boolean(_) | WeakType::Null => match rhs {
+                WeakType::String(_) | WeakType::Object(_) | WeakType::Array(_) => {
+                    WeakType::from(self.coerce_to_string() + &rhs.coerce_to_string())
+                }
+                _ => WeakType::from(self.coerce_to_number() + rhs.coerce_to_number()),
+            },
+        }
+    }
+}
+
+impl std::ops::Sub for &WeakType {
+    type Output = WeakType;
+
+    fn sub(self, rhs: &WeakType) -> Self::Output {
+        WeakType::from(self.coerce_to_number() - rhs.coerce_to_number())
+    }
+}
+
+impl std::ops::Mul for &WeakType {
+    type Output = WeakType;
+
+    fn mul(self, rhs: &WeakType) -> Self::Output {
+        WeakType::from(self.coerce_to_number() * rhs.coerce_to_number())
+    }
+}
+
+impl std::ops::Div for &WeakType {
+    type Output = WeakType;
+
+    fn div(self, rhs: &WeakType) -> Self::Output {
+        WeakType::from(self.coerce_to_number() / rhs.coerce_to_number())
+    }
+}
+
+impl std::ops::Rem for &WeakType {
+    type Output = WeakType;
+
+    fn rem(self, rhs: &WeakType) -> Self::Output {
+        WeakType::from(self.coerce_to_number() % rhs.coerce_to_number())
+    }
+}
+
+impl std::ops::Neg for &WeakType {
+    type Output = WeakType;
+
+    fn neg(self) -> Self::Output {
+        WeakType::from(-self.coerce_to_number())
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use super::*;
+
+    #[test]
+    fn sub_never_concatenates_strings() {
+        assert!((&WeakType::from("foo") - &WeakType::from(1.0)).coerce_to_number().is_nan());
+    }
+
+    #[test]
+    fn mul_coerces_both_operands_to_numbers() {
+        assert_eq!(&WeakType::from("10") * &WeakType::from("2"), WeakType::from(20.0));
+    }
+
+    #[test]
+    fn mul_by_an_empty_array_coerces_through_the_string_path_to_zero() {
+        let empty = WeakType::from(Vec::<WeakType>::new());
+        assert_eq!(&empty * &WeakType::from(1.0), WeakType::from(0.0));
+    }
+
+    #[test]
+    fn div_and_rem_coerce_both_operands_to_numbers() {
+        assert_eq!(&WeakType::from("10") / &WeakType::from("2"), WeakType::from(5.0));
+        assert_eq!(&WeakType::from("10") % &WeakType::from("3"), WeakType::from(1.0));
+    }
+
+    #[test]
+    fn neg_negates_the_numeric_coercion_of_each_variant() {
+        assert_eq!(-&WeakType::from(5.0), WeakType::from(-5.0));
+        assert_eq!(-&WeakType::from("5"), WeakType::from(-5.0));
+        assert_eq!(-&WeakType::from(true), WeakType::from(-1.0));
+        assert_eq!(-&WeakType::Null, WeakType::from(0.0));
+        assert!((-&WeakType::Undefined).coerce_to_number().is_nan());
+    }
+}
+
+impl std::ops::Index<&str> for WeakType {
+    type Output = WeakType;
+    fn index(&self, key: &str) -> &<Self as std::ops::Index<&str>>::Output {
+        match self {
+            WeakType::String(_) => &WeakType::Undefined,
+            WeakType::Number(_) => &WeakType::Undefined,
+            WeakType::Boolean(_) => &WeakType::Undefined,
+            WeakType::Null => &WeakType::Undefined,
+            WeakType::Object(obj) => {
+                if obj.0.contains_key(key) {
+                    &obj.0[key]
+                } else {
+                    &WeakType::Undefined
+                }
+            }
+            WeakType::Undefined => self,
+            WeakType::Array(_) => &WeakType::Undefined,
+        }
+    }
+}
+
+impl WeakType {
+    pub(crate) fn from<T>(value: T) -> WeakType
+    where
+        T: IntoWeakType,
+    {
+        value.into()
+    }
+
+    pub(crate) fn coerce_to_number(&self) -> f64 {
+        fn parse_numeric_string(str: &str) -> f64 {
+            let trimmed = str.trim();
+            if trimmed.is_empty() {
+                0.0
+            } else {
+                trimmed.parse::<f64>().unwrap_or(f64::NAN)
+            }
+        }
+
+        match self {
+            WeakType::String(str) => parse_numeric_string(str),
+            WeakType::Number(num) => *num,
+            WeakType::Boolean(bool) => {
+                if *bool {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            WeakType::Null => 0.0,
+            WeakType::Undefined => f64::NAN,
+            // Objects/Arrays go through the same string coercion used by
+            // `coerce_to_string`, so e.g. an empty array ("") coerces to 0
+            // and a single-element array like `[5]` coerces to 5, mirroring
+            // how JS routes these through ToPrimitive before ToNumber.
+            WeakType::Object(_) | WeakType::Array(_) => parse_numeric_string(&self.coerce_to_string()),
+        }
+    }
+
+    pub(crate) fn coerce_to_string(&self) -> String {
+        match self {
+            WeakType::String(str) => str.to_string(),
+            WeakType::Number(num) => num.to_string(),
+            WeakType::Boolean(bool) => bool.to_string(),
+            WeakType::Object(_) => String::from("[object Object]"),
+            WeakType::Null => String::from("null"),
+            WeakType::Undefined => String::from("undefined"),
+            WeakType::Array(arr) => arr.join(", "),
+        }
+    }
+
+    pub(crate) fn coerce_to_boolean(&self) -> bool {
+        match self {
+            WeakType::String(str) => !str.is_empty(),
+            WeakType::Number(num) => *num != 0.0 && !num.is_nan(),
+            WeakType::Boolean(bool) => *bool,
+            WeakType::Object(_) => true,
+            WeakType::Array(_) => true,
+            WeakType::Null => false,
+            WeakType::Undefined => false,
+        }
+    }
+
+    pub fn and(&self, other: &WeakType) -> WeakType {
+        if self.coerce_to_boolean() {
+            other.to_owned()
+        } else {
+            self.to_owned()
+        }
+    }
+
+    pub fn or(&self, other: &WeakType) -> WeakType {
+        if self.coerce_to_boolean() {
+            self.to_owned()
+        } else {
+            other.to_owned()
+        }
+    }
+
+    /// JS `===`. Objects and Arrays have no notion of reference identity in
+    /// this model, so two instances are never strictly equal even when their
+    /// contents match.
+    pub fn strict_equals(&self, other: &WeakType) -> bool {
+        match (self, other) {
+            (WeakType::Number(self_num), WeakType::Number(other_num)) => self_num == other_num,
+            (WeakType::String(self_str), WeakType::String(other_str)) => self_str == other_str,
+            (WeakType::Boolean(self_bool), WeakType::Boolean(other_bool)) => self_bool == other_bool,
+            (WeakType::Null, WeakType::Null) => true,
+            (WeakType::Undefined, WeakType::Undefined) => true,
+            (WeakType::Object(_), WeakType::Object(_)) => false,
+            (WeakType::Array(_), WeakType::Array(_)) => false,
+            _ => false,
+        }
+    }
+
+    /// Serializes to JSON, mirroring `JSON.stringify`: `Undefined` values are
+    /// dropped from objects, turned into `null` inside arrays, and rendered
+    /// as the literal `"null"` at the top level. Numbers that don't fit JSON
+    /// (`NaN`, `Infinity`) also become `null`, just as `JSON.stringify` does.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.to_json_value())
+            .expect("WeakType always converts to a valid JSON value")
+    }
+
+    fn to_json_value(&self) -> Value {
+        match self {
+            WeakType::String(str) => Value::String(str.clone()),
+            WeakType::Number(num) => {
+                serde_json::Number::from_f64(*num).map_or(Value::Null, Value::Number)
+            }
+            WeakType::Boolean(bool) => Value::Bool(*bool),
+            WeakType::Null | WeakType::Undefined => Value::Null,
+            WeakType::Array(arr) => {
+                Value::Array(arr.0.iter().map(WeakType::to_json_value).collect())
+            }
+            WeakType::Object(obj) => Value::Object(
+                obj.0
+                    .iter()
+                    .filter(|(_, value)| !matches!(value, WeakType::Undefined))
+                    .map(|(key, value)| (key.clone(), value.to_json_value()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Deserializes from JSON. Inverse of [`WeakType::to_json`]: JSON `null`
+    /// becomes `WeakType::Null`, never `Undefined`, matching `JSON.parse`.
+    pub fn from_json(src: &str) -> Result<WeakType, serde_json::Error> {
+        Ok(WeakType::from_json_value(serde_json::from_str(src)?))
+    }
+
+    fn from_json_value(value: Value) -> WeakType {
+        match value {
+            Value::Null => WeakType::Null,
+            Value::Bool(bool) => WeakType::Boolean(bool),
+            Value::Number(num) => WeakType::Number(num.as_f64().unwrap_or(f64::NAN)),
+            Value::String(str) => WeakType::String(str),
+            Value::Array(arr) => {
+                WeakType::from(arr.into_iter().map(WeakType::from_json_value).collect::<Vec<_>>())
+            }
+            Value::Object(map) => Object::from_values(
+                map.into_iter()
+                    .map(|(key, value)| (key, WeakType::from_json_value(value)))
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// `Array.prototype.map`. `Undefined` for anything that isn't an array.
+    pub fn map(&self, f: impl Fn(&WeakType) -> WeakType) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.map(f)),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.filter`. `Undefined` for anything that isn't an array.
+    pub fn filter(&self, f: impl Fn(&WeakType) -> bool) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.filter(f)),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.reduce`. `Undefined` for anything that isn't an array.
+    pub fn reduce(&self, f: impl Fn(WeakType, &WeakType) -> WeakType, init: WeakType) -> WeakType {
+        match self {
+            WeakType::Array(arr) => arr.reduce(f, init),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.forEach`. A no-op for anything that isn't an array.
+    pub fn for_each(&self, f: impl FnMut(&WeakType)) {
+        if let WeakType::Array(arr) = self {
+            arr.for_each(f);
+        }
+    }
+
+    /// `Array.prototype.join`. `Undefined` for anything that isn't an array.
+    pub fn join(&self, sep: &str) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.join(sep)),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.indexOf`, using strict equality. `Undefined` for
+    /// anything that isn't an array.
+    pub fn index_of(&self, value: &WeakType) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.index_of(value)),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.includes`, using strict equality. `Undefined` for
+    /// anything that isn't an array.
+    pub fn includes(&self, value: &WeakType) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.includes(value)),
+            _ => WeakType::Undefined,
+        }
+    }
+
+    /// `Array.prototype.push`. A no-op for anything that isn't an array.
+    pub fn push(&mut self, value: WeakType) {
+        if let WeakType::Array(arr) = self {
+            arr.push(value);
+        }
+    }
+
+    /// `Array.prototype.length`. `Undefined` for anything that isn't an array.
+    pub fn length(&self) -> WeakType {
+        match self {
+            WeakType::Array(arr) => WeakType::from(arr.length()),
+            _ => WeakType::Undefined,
+        }
+    }
+}
+
+impl std::ops::Not for &WeakType {
+    type Output = WeakType;
+
+    fn not(self) -> Self::Output {
+        WeakType::from(!self.coerce_to_boolean())
+    }
+}
+
+#[cfg(test)]
+mod truthiness_tests {
+    use super::*;
+
+    #[test]
+    fn falsy_values_coerce_to_false() {
+        assert!(!WeakType::from(false).coerce_to_boolean());
+        assert!(!WeakType::from(0.0).coerce_to_boolean());
+        assert!(!WeakType::from(f64::NAN).coerce_to_boolean());
+        assert!(!WeakType::from("").coerce_to_boolean());
+        assert!(!WeakType::Null.coerce_to_boolean());
+        assert!(!WeakType::Undefined.coerce_to_boolean());
+    }
+
+    #[test]
+    fn objects_and_arrays_are_truthy_even_when_empty() {
+        assert!(WeakType::from(Vec::<WeakType>::new()).coerce_to_boolean());
+        assert!(Object::from_values([]).coerce_to_boolean());
+    }
+
+    #[test]
+    fn not_negates_truthiness() {
+        assert_eq!(!&WeakType::from(true), WeakType::from(false));
+        assert_eq!(!&WeakType::Null, WeakType::from(true));
+    }
+
+    #[test]
+    fn and_returns_the_left_operand_if_falsy_else_the_right() {
+        assert_eq!(WeakType::Null.and(&WeakType::from(1.0)), WeakType::Null);
+        assert_eq!(WeakType::from(1.0).and(&WeakType::from(2.0)), WeakType::from(2.0));
+    }
+
+    #[test]
+    fn or_returns_the_left_operand_if_truthy_else_the_right() {
+        assert_eq!(WeakType::from(1.0).or(&WeakType::from(2.0)), WeakType::from(1.0));
+        assert_eq!(WeakType::Null.or(&WeakType::from(2.0)), WeakType::from(2.0));
+    }
+}
+
+impl PartialEq for WeakType {
+    /// JS abstract (`==`) equality: same-variant operands defer to
+    /// `strict_equals`, `Null`/`Undefined` are mutually equal and nothing
+    /// else, Booleans are replaced by their numeric coercion and re-compared,
+    /// and Objects/Arrays are replaced by their string coercion and
+    /// re-compared — each rule strictly reduces towards the Number/String
+    /// base case, so the recursion always terminates.
+    fn eq(&self, other: &WeakType) -> bool {
+        match (self, other) {
+            (WeakType::Number(_), WeakType::Number(_))
+            | (WeakType::String(_), WeakType::String(_))
+            | (WeakType::Boolean(_), WeakType::Boolean(_))
+            | (WeakType::Null, WeakType::Null)
+            | (WeakType::Undefined, WeakType::Undefined)
+            | (WeakType::Object(_), WeakType::Object(_))
+            | (WeakType::Array(_), WeakType::Array(_)) => self.strict_equals(other),
+
+            (WeakType::Null, WeakType::Undefined) | (WeakType::Undefined, WeakType::Null) => true,
+
+            (WeakType::Number(_), WeakType::String(_))
+            | (WeakType::String(_), WeakType::Number(_)) => {
+                self.coerce_to_number() == other.coerce_to_number()
+            }
+
+            (WeakType::Boolean(_), _) => WeakType::from(self.coerce_to_number()) == *other,
+            (_, WeakType::Boolean(_)) => *self == WeakType::from(other.coerce_to_number()),
+
+            (WeakType::Object(_) | WeakType::Array(_), WeakType::Number(_) | WeakType::String(_))
+            | (WeakType::Number(_) | WeakType::String(_), WeakType::Object(_) | WeakType::Array(_)) => {
+                if matches!(self, WeakType::Object(_) | WeakType::Array(_)) {
+                    WeakType::from(self.coerce_to_string()) == *other
+                } else {
+                    *self == WeakType::from(other.coerce_to_string())
+                }
+            }
+
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod equality_tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_strings_compare_by_numeric_value() {
+        assert_eq!(WeakType::from(1.0), WeakType::from("1"));
+        assert_ne!(WeakType::from(1.0), WeakType::from("2"));
+    }
+
+    #[test]
+    fn null_and_undefined_are_abstractly_equal_to_each_other_only() {
+        assert_eq!(WeakType::Null, WeakType::Undefined);
+        assert_ne!(WeakType::Null, WeakType::from(0.0));
+        assert_ne!(WeakType::Undefined, WeakType::from(false));
+    }
+
+    #[test]
+    fn booleans_coerce_to_numbers_before_comparing() {
+        assert_eq!(WeakType::from(true), WeakType::from(1.0));
+        assert_eq!(WeakType::from(false), WeakType::from("0"));
+        assert_eq!(WeakType::from(true), WeakType::from("1"));
+    }
+
+    #[test]
+    fn arrays_coerce_to_strings_before_comparing() {
+        let single = WeakType::from(vec![WeakType::from(5.0)]);
+        assert_eq!(single, WeakType::from(5.0));
+        assert_eq!(single, WeakType::from("5"));
+
+        let empty = WeakType::from(Vec::<WeakType>::new());
+        assert_eq!(empty, WeakType::from(0.0));
+    }
+
+    #[test]
+    fn objects_coerce_to_their_string_form_before_comparing() {
+        let obj = Object::from_values([("a", WeakType::from(1.0))]);
+        assert_ne!(obj, WeakType::from(0.0));
+        assert_eq!(obj, WeakType::from("[object Object]"));
+    }
+
+    #[test]
+    fn strict_equals_never_matches_across_objects_or_arrays() {
+        let first = Object::from_values([("a", WeakType::from(1.0))]);
+        let second = Object::from_values([("a", WeakType::from(1.0))]);
+        assert!(!first.strict_equals(&second));
+
+        let arr = WeakType::from(vec![WeakType::from(1.0)]);
+        assert!(!arr.strict_equals(&arr.clone()));
+    }
+}
+
+pub trait IntoWeakType {
+    fn into(self) -> WeakType;
+}
+
+impl IntoWeakType for f64 {
+    fn into(self) -> WeakType {
+        WeakType::Number(self)
+    }
+}
+
+impl IntoWeakType for i32 {
+    fn into(self) -> WeakType {
+        WeakType::Number(f64::from(self))
+    }
+}
+
+impl IntoWeakType for bool {
+    fn into(self) -> WeakType {
+        WeakType::Boolean(self)
+    }
+}
+
+impl IntoWeakType for &str {
+    fn into(self) -> WeakType {
+        WeakType::String(self.to_string())
+    }
+}
+
+impl IntoWeakType for String {
+    fn into(self) -> WeakType {
+        WeakType::String(self)
+    }
+}
+
+impl IntoWeakType for HashMap<&'static str, WeakType> {
+    fn into(self) -> WeakType {
+        let owned = self.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+        WeakType::Object(Object(owned))
+    }
+}
+
+impl IntoWeakType for HashMap<String, WeakType> {
+    fn into(self) -> WeakType {
+        WeakType::Object(Object(self))
+    }
+}
+
+impl IntoWeakType for Object {
+    fn into(self) -> WeakType {
+        WeakType::Object(self)
+    }
+}
+
+impl IntoWeakType for Vec<WeakType> {
+    fn into(self) -> WeakType {
+        WeakType::Array(Array(self))
+    }
+}
+
+impl IntoWeakType for Array {
+    fn into(self) -> WeakType {
+        WeakType::Array(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Array(Vec<WeakType>);
+
+impl Array {
+    pub fn from(arr: &[WeakType]) -> WeakType {
+        WeakType::from(Array(arr.to_vec()))
+    }
+
+    pub fn map(&self, f: impl Fn(&WeakType) -> WeakType) -> Array {
+        Array(self.into_iter().map(f).collect())
+    }
+
+    pub fn filter(&self, f: impl Fn(&WeakType) -> bool) -> Array {
+        Array(self.into_iter().filter(|value| f(value)).cloned().collect())
+    }
+
+    pub fn reduce(&self, f: impl Fn(WeakType, &WeakType) -> WeakType, init: WeakType) -> WeakType {
+        self.into_iter().fold(init, f)
+    }
+
+    pub fn for_each(&self, f: impl FnMut(&WeakType)) {
+        self.into_iter().for_each(f);
+    }
+
+    pub fn join(&self, sep: &str) -> String {
+        self.into_iter()
+            .map(WeakType::coerce_to_string)
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+
+    pub fn index_of(&self, value: &WeakType) -> i32 {
+        self.into_iter()
+            .position(|item| item.strict_equals(value))
+            .map_or(-1, |index| index as i32)
+    }
+
+    pub fn includes(&self, value: &WeakType) -> bool {
+        self.index_of(value) != -1
+    }
+
+    pub fn push(&mut self, value: WeakType) {
+        self.0.push(value);
+    }
+
+    pub fn length(&self) -> i32 {
+        self.0.len() as i32
+    }
+}
+
+impl<'a> IntoIterator for &'a Array {
+    type Item = &'a WeakType;
+    type IntoIter = std::slice::Iter<'a, WeakType>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod array_tests {
+    use super::*;
+
+    fn arr(values: &[f64]) -> WeakType {
+        WeakType::from(values.iter().map(|n| WeakType::from(*n)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn map_filter_and_reduce_produce_expected_results() {
+        // Arrays are never strictly (or abstractly) equal to one another, so
+        // compare via their string coercion instead of `==`.
+        let doubled = arr(&[1.0, 2.0, 3.0]).map(|v| WeakType::from(v.coerce_to_number() * 2.0));
+        assert_eq!(doubled.coerce_to_string(), arr(&[2.0, 4.0, 6.0]).coerce_to_string());
+
+        let evens = arr(&[1.0, 2.0, 3.0, 4.0]).filter(|v| v.coerce_to_number() % 2.0 == 0.0);
+        assert_eq!(evens.coerce_to_string(), arr(&[2.0, 4.0]).coerce_to_string());
+
+        let sum = arr(&[1.0, 2.0, 3.0]).reduce(
+            |acc, v| WeakType::from(acc.coerce_to_number() + v.coerce_to_number()),
+            WeakType::from(0.0),
+        );
+        assert_eq!(sum, WeakType::from(6.0));
+    }
+
+    #[test]
+    fn join_uses_the_given_separator() {
+        assert_eq!(arr(&[1.0, 2.0, 3.0]).join(" - "), WeakType::from("1 - 2 - 3"));
+    }
+
+    #[test]
+    fn index_of_and_includes_use_strict_not_abstract_equality() {
+        let values = arr(&[1.0, 2.0, 3.0]);
+        assert_eq!(values.index_of(&WeakType::from(2.0)), WeakType::from(1.0));
+        assert_eq!(values.includes(&WeakType::from(2.0)), WeakType::from(true));
+
+        // "2" abstractly equals 2.0 but isn't strictly equal to it.
+        assert_eq!(values.index_of(&WeakType::from("2")), WeakType::from(-1.0));
+        assert_eq!(values.includes(&WeakType::from("2")), WeakType::from(false));
+    }
+
+    #[test]
+    fn non_array_variants_return_undefined() {
+        let not_an_array = WeakType::from("foo");
+        assert_eq!(not_an_array.map(|v| v.clone()), WeakType::Undefined);
+        assert_eq!(not_an_array.filter(|_| true), WeakType::Undefined);
+        assert_eq!(not_an_array.join(", "), WeakType::Undefined);
+        assert_eq!(not_an_array.index_of(&WeakType::from(1.0)), WeakType::Undefined);
+        assert_eq!(not_an_array.includes(&WeakType::from(1.0)), WeakType::Undefined);
+        assert_eq!(not_an_array.length(), WeakType::Undefined);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Object(HashMap<String, WeakType>);
+
+pub trait FromValues<T> {
+    fn from_values(value: T) -> WeakType;
+}
+
+impl<const N: usize> FromValues<[(&'static str, WeakType); N]> for Object {
+    fn from_values(arr: [(&'static str, WeakType); N]) -> WeakType {
+        WeakType::from(Object(HashMap::from_iter(
+            arr.into_iter().map(|(k, v)| (k.to_string(), v)),
+        )))
+    }
+}
+
+impl FromValues<Vec<(String, WeakType)>> for Object {
+    fn from_values(values: Vec<(String, WeakType)>) -> WeakType {
+        WeakType::from(Object(HashMap::from_iter(values)))
+    }
+}
+
+/// Coerces any value into a `WeakType::Number`, JS `Number(...)`-style.
+#[allow(non_snake_case)]
+pub fn Number(n: impl IntoWeakType) -> WeakType {
+    WeakType::from(n.into().coerce_to_number())
+}
+
+/// Coerces any value into a `WeakType::String`, JS `String(...)`-style.
+#[allow(non_snake_case)]
+pub fn String(s: impl IntoWeakType) -> WeakType {
+    WeakType::from(s.into().coerce_to_string())
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_mixed_object_through_json() {
+        let planets = Object::from_values([
+            ("Mercury", Number(0.4)),
+            ("Venus", Number(0.7)),
+            ("moons", WeakType::from(vec![String("Phobos"), String("Deimos")])),
+        ]);
+
+        let json = planets.to_json();
+        let parsed = WeakType::from_json(&json).expect("round-tripped JSON should parse");
+
+        assert_eq!(parsed.to_json(), json);
+    }
+
+    #[test]
+    fn undefined_becomes_null_through_json() {
+        assert_eq!(WeakType::Undefined.to_json(), "null");
+        assert_eq!(WeakType::from_json("null").unwrap().to_json(), "null");
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{FromValues, Object, WeakType};
+
+/// A JavaScript-like expression, as produced by [`parse`] and consumed by
+/// [`eval`]. Covers just enough grammar for `parse_and_eval` to make sense of
+/// expressions like `"1" + [2, 3] * 2`.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(f64),
+    String(String),
+    Array(Vec<Expr>),
+    Object(Vec<(String, Expr)>),
+    Member(Box<Expr>, String),
+    Index(Box<Expr>, Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    String(String),
+    Ident(String),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Comma,
+    Colon,
+    Dot,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+}
+
+fn tokenize(src: &str) -> Vec<Token> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            '{' => {
+                tokens.push(Token::LBrace);
+                i += 1;
+            }
+            '}' => {
+                tokens.push(Token::RBrace);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            ':' => {
+                tokens.push(Token::Colon);
+                i += 1;
+            }
+            '.' => {
+                tokens.push(Token::Dot);
+                i += 1;
+            }
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Slash);
+                i += 1;
+            }
+            '%' => {
+                tokens.push(Token::Percent);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let quote = c;
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                tokens.push(Token::String(chars[start..i].iter().collect()));
+                i += 1; // skip closing quote
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let number: String = chars[start..i].iter().collect();
+                tokens.push(Token::Number(number.parse().expect("malformed number literal")));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            _ => panic!("unexpected character '{}' in expression", c),
+        }
+    }
+
+    tokens
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Parser {
+        Parser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &Token) {
+        let token = self.next();
+        assert!(
+            &token == expected,
+            "expected {:?}, found {:?}",
+            expected,
+            token
+        );
+    }
+
+    fn parse_expr(&mut self) -> Expr {
+        let mut lhs = self.parse_term();
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => BinOp::Add,
+                Some(Token::Minus) => BinOp::Sub,
+                _ => break,
+            };
+            self.next();
+            let rhs = self.parse_term();
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        lhs
+    }
+
+    fn parse_term(&mut self) -> Expr {
+        let mut lhs = self.parse_postfix();
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => BinOp::Mul,
+                Some(Token::Slash) => BinOp::Div,
+                Some(Token::Percent) => BinOp::Rem,
+                _ => break,
+            };
+            self.next();
+            let rhs = self.parse_postfix();
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        lhs
+    }
+
+    fn parse_postfix(&mut self) -> Expr {
+        let mut expr = self.parse_primary();
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.next();
+                    let name = match self.next() {
+                        Token::Ident(name) => name,
+                        token => panic!("expected identifier after '.', found {:?}", token),
+                    };
+                    expr = Expr::Member(Box::new(expr), name);
+                }
+                Some(Token::LBracket) => {
+                    self.next();
+                    let index = self.parse_expr();
+                    self.expect(&Token::RBracket);
+                    expr = Expr::Index(Box::new(expr), Box::new(index));
+                }
+                _ => break,
+            }
+        }
+        expr
+    }
+
+    fn parse_primary(&mut self) -> Expr {
+        match self.next() {
+            Token::Number(num) => Expr::Number(num),
+            Token::String(str) => Expr::String(str),
+            Token::LParen => {
+                let expr = self.parse_expr();
+                self.expect(&Token::RParen);
+                expr
+            }
+            Token::LBracket => {
+                let mut items = Vec::new();
+                if self.peek() != Some(&Token::RBracket) {
+                    items.push(self.parse_expr());
+                    while self.peek() == Some(&Token::Comma) {
+                        self.next();
+                        items.push(self.parse_expr());
+                    }
+                }
+                self.expect(&Token::RBracket);
+                Expr::Array(items)
+            }
+            Token::LBrace => {
+                let mut fields = Vec::new();
+                if self.peek() != Some(&Token::RBrace) {
+                    fields.push(self.parse_object_field());
+                    while self.peek() == Some(&Token::Comma) {
+                        self.next();
+                        fields.push(self.parse_object_field());
+                    }
+                }
+                self.expect(&Token::RBrace);
+                Expr::Object(fields)
+            }
+            token => panic!("unexpected token {:?} in expression", token),
+        }
+    }
+
+    fn parse_object_field(&mut self) -> (String, Expr) {
+        let key = match self.next() {
+            Token::Ident(name) => name,
+            Token::String(str) => str,
+            token => panic!("expected object key, found {:?}", token),
+        };
+        self.expect(&Token::Colon);
+        (key, self.parse_expr())
+    }
+}
+
+/// Parses a JavaScript-like expression into an [`Expr`] AST.
+pub fn parse(src: &str) -> Expr {
+    let mut parser = Parser::new(tokenize(src));
+    parser.parse_expr()
+}
+
+/// Evaluates an [`Expr`] into a `WeakType`, using the same coercions and
+/// operator impls as the rest of the crate.
+pub fn eval(expr: &Expr) -> WeakType {
+    match expr {
+        Expr::Number(num) => WeakType::from(*num),
+        Expr::String(str) => WeakType::from(str.clone()),
+        Expr::Array(items) => WeakType::from(items.iter().map(eval).collect::<Vec<_>>()),
+        Expr::Object(fields) => {
+            let values = fields
+                .iter()
+                .map(|(key, value)| (key.clone(), eval(value)))
+                .collect::<Vec<_>>();
+            Object::from_values(values)
+        }
+        Expr::Member(target, name) => (&eval(target))[name.as_str()].clone(),
+        Expr::Index(target, index) => {
+            let key = eval(index).coerce_to_string();
+            (&eval(target))[key.as_str()].clone()
+        }
+        Expr::Binary(op, lhs, rhs) => {
+            let lhs = eval(lhs);
+            let rhs = eval(rhs);
+            match op {
+                BinOp::Add => &lhs + &rhs,
+                BinOp::Sub => &lhs - &rhs,
+                BinOp::Mul => &lhs * &rhs,
+                BinOp::Div => &lhs / &rhs,
+                BinOp::Rem => &lhs % &rhs,
+            }
+        }
+    }
+}
+
+/// Parses and evaluates a JavaScript-like expression in one call, e.g.
+/// `parse_and_eval("\"1\" + [2, 3] * 2")`.
+pub fn parse_and_eval(src: &str) -> WeakType {
+    eval(&parse(src))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn respects_multiplication_precedence_over_addition() {
+        // "1" + [2, 3] * 2 -> "1" + ([2, 3] * 2) -> "1" + NaN -> NaN,
+        // since [2, 3] coerces to "2, 3", which isn't numeric.
+        assert_eq!(parse_and_eval("\"1\" + [2, 3] * 2").coerce_to_string(), "NaN");
+    }
+
+    #[test]
+    fn evaluates_member_access_on_object_literals() {
+        assert_eq!(
+            parse_and_eval("{ a: 1, b: 2 }.b").coerce_to_string(),
+            "2"
+        );
+        assert_eq!(
+            parse_and_eval("{ a: 1 }[\"missing\"]").coerce_to_string(),
+            "undefined"
+        );
+    }
+
+    #[test]
+    fn parenthesized_expressions_override_precedence() {
+        assert_eq!(parse_and_eval("(1 + 2) * 3").coerce_to_string(), "9");
+    }
+}